@@ -0,0 +1,250 @@
+//! Word-to-language classification over the enabled dictionaries.
+//!
+//! A query first gets a fast exact-membership check against each enabled
+//! language's word list. For out-of-dictionary input, this falls back to
+//! character n-gram scoring (in the style of whatlang/lingua): per-language
+//! character bi/trigram frequencies are computed once from that language's
+//! [`words::get`] list, and a query is scored as the sum of log-probabilities
+//! of its n-grams under each language's model.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::{words, Lang};
+
+/// Add-k smoothing pseudo-count folded into the normalizing total when
+/// turning n-gram counts into probabilities.
+///
+/// Keeping this below the smallest real count (1) means an unseen gram's
+/// probability floor (`ADD_K / total`) always stays below the model's
+/// smallest *observed* probability (`1 / total`). A flat floor constant
+/// doesn't have that guarantee: these per-language corpora are tiny
+/// (~40-50 words), so a constant large enough to matter can easily beat
+/// the true probability of a rare-but-real gram, making `rank` favor
+/// "never seen this" over "saw this exactly once".
+const ADD_K: f64 = 0.5;
+
+struct Model {
+    trigrams: HashMap<[char; 3], f64>,
+    bigrams: HashMap<[char; 2], f64>,
+    unigrams: HashMap<char, f64>,
+    trigram_floor: f64,
+    bigram_floor: f64,
+    unigram_floor: f64,
+}
+
+/// All languages enabled via crate features, in a fixed order.
+///
+/// This order is relied on by [`rank`] to keep its output deterministic
+/// across runs, so keep it append-only rather than interleaving or
+/// reordering entries.
+///
+/// Split into two definitions rather than one function with a `let mut
+/// langs = Vec::new()` followed by `#[cfg]`-gated `.extend()` calls: with
+/// no language feature enabled (a combination the `[features]` table
+/// doesn't forbid), none of those calls would exist and `langs` would
+/// never need to be mutable, failing `cargo clippy --no-default-features
+/// --features std` on `unused_mut`.
+#[cfg(any(
+    feature = "de",
+    feature = "en",
+    feature = "es",
+    feature = "fr",
+    feature = "zh"
+))]
+fn all_langs() -> Vec<Lang> {
+    let mut langs = Vec::new();
+    #[cfg(feature = "de")]
+    langs.extend([Lang::De]);
+    #[cfg(feature = "en")]
+    langs.extend([Lang::En]);
+    #[cfg(feature = "es")]
+    langs.extend([Lang::Es]);
+    #[cfg(feature = "fr")]
+    langs.extend([Lang::Fr]);
+    #[cfg(feature = "zh")]
+    langs.extend([Lang::Zh]);
+    langs
+}
+
+#[cfg(not(any(
+    feature = "de",
+    feature = "en",
+    feature = "es",
+    feature = "fr",
+    feature = "zh"
+)))]
+fn all_langs() -> Vec<Lang> {
+    Vec::new()
+}
+
+/// Converts raw counts into add-k-smoothed log-probabilities, returning the
+/// map alongside the floor log-probability for a gram that was never seen
+/// at all (so callers don't need to recompute `total` to look it up).
+fn log_probabilities<K: Eq + core::hash::Hash>(counts: HashMap<K, u32>) -> (HashMap<K, f64>, f64) {
+    let total: f64 = counts.values().sum::<u32>() as f64;
+    let denom = total + ADD_K;
+    let probabilities = counts
+        .into_iter()
+        .map(|(gram, count)| (gram, (count as f64 / denom).ln()))
+        .collect();
+    let floor = (ADD_K / denom).ln();
+    (probabilities, floor)
+}
+
+fn build_model(lang: Lang) -> Model {
+    let mut trigram_counts: HashMap<[char; 3], u32> = HashMap::new();
+    let mut bigram_counts: HashMap<[char; 2], u32> = HashMap::new();
+    let mut unigram_counts: HashMap<char, u32> = HashMap::new();
+
+    for word in words::get(lang) {
+        let chars: Vec<char> = word.chars().collect();
+        for c in &chars {
+            *unigram_counts.entry(*c).or_insert(0) += 1;
+        }
+        for window in chars.windows(2) {
+            *bigram_counts.entry([window[0], window[1]]).or_insert(0) += 1;
+        }
+        for window in chars.windows(3) {
+            *trigram_counts
+                .entry([window[0], window[1], window[2]])
+                .or_insert(0) += 1;
+        }
+    }
+
+    let (trigrams, trigram_floor) = log_probabilities(trigram_counts);
+    let (bigrams, bigram_floor) = log_probabilities(bigram_counts);
+    let (unigrams, unigram_floor) = log_probabilities(unigram_counts);
+
+    Model {
+        trigrams,
+        bigrams,
+        unigrams,
+        trigram_floor,
+        bigram_floor,
+        unigram_floor,
+    }
+}
+
+fn models() -> &'static HashMap<Lang, Model> {
+    static MODELS: OnceLock<HashMap<Lang, Model>> = OnceLock::new();
+    MODELS.get_or_init(|| {
+        all_langs()
+            .into_iter()
+            .map(|lang| (lang, build_model(lang)))
+            .collect()
+    })
+}
+
+/// Score for an empty word, which has no n-grams to look up; every model
+/// gets this same score for it.
+const UNSEEN: f64 = f64::MIN;
+
+fn score(word: &str, model: &Model) -> f64 {
+    let chars: Vec<char> = word.chars().collect();
+
+    if chars.len() >= 3 {
+        chars
+            .windows(3)
+            .map(|w| {
+                model
+                    .trigrams
+                    .get(&[w[0], w[1], w[2]])
+                    .copied()
+                    .unwrap_or(model.trigram_floor)
+            })
+            .sum()
+    } else if chars.len() == 2 {
+        model
+            .bigrams
+            .get(&[chars[0], chars[1]])
+            .copied()
+            .unwrap_or(model.bigram_floor)
+    } else if let Some(&c) = chars.first() {
+        model
+            .unigrams
+            .get(&c)
+            .copied()
+            .unwrap_or(model.unigram_floor)
+    } else {
+        UNSEEN
+    }
+}
+
+/// Scores `word` against every enabled language's n-gram model, most
+/// likely first.
+///
+/// Ties are broken by [`all_langs`]'s fixed order rather than by
+/// iterating `models()` directly: `HashMap` iteration order is randomized
+/// per process, which would otherwise make the winner for a tied score
+/// (common for out-of-dictionary input) change from run to run.
+pub(crate) fn rank(word: &str) -> Vec<(Lang, f64)> {
+    let lower = word.to_lowercase();
+    let model_map = models();
+    let mut scores: Vec<(Lang, f64)> = all_langs()
+        .into_iter()
+        .map(|lang| {
+            let model = model_map
+                .get(&lang)
+                .expect("models() builds one entry per all_langs() member");
+            (lang, score(&lower, model))
+        })
+        .collect();
+    scores.sort_by(|a, b| b.1.partial_cmp(&a.1).expect("scores are never NaN"));
+    scores
+}
+
+/// Guesses which enabled language `word` belongs to.
+pub(crate) fn detect(word: &str) -> Option<Lang> {
+    let lower = word.to_lowercase();
+    if let Some(lang) = all_langs()
+        .into_iter()
+        .find(|&lang| words::get(lang).contains(&lower.as_str()))
+    {
+        return Some(lang);
+    }
+
+    rank(word).into_iter().next().map(|(lang, _)| lang)
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "de",
+        feature = "en",
+        feature = "es",
+        feature = "fr",
+        feature = "zh"
+    )
+))]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "en")]
+    #[test]
+    fn ranks_in_dictionary_words_top() {
+        assert_eq!(rank("apple").first().map(|(lang, _)| *lang), Some(Lang::En));
+        assert_eq!(rank("quiver").first().map(|(lang, _)| *lang), Some(Lang::En));
+    }
+
+    #[cfg(all(
+        feature = "de",
+        feature = "en",
+        feature = "es",
+        feature = "fr",
+        feature = "zh"
+    ))]
+    #[test]
+    fn ranks_in_dictionary_words_above_every_other_language() {
+        assert_eq!(rank("apple").first().map(|(lang, _)| *lang), Some(Lang::En));
+        assert_eq!(rank("agua").first().map(|(lang, _)| *lang), Some(Lang::Es));
+        assert_eq!(rank("maison").first().map(|(lang, _)| *lang), Some(Lang::Fr));
+    }
+
+    #[cfg(feature = "en")]
+    #[test]
+    fn detect_agrees_with_ranks_top_pick_for_nonsense_input() {
+        let word = "zzqxw";
+        assert_eq!(detect(word), rank(word).first().map(|(lang, _)| *lang));
+    }
+}
@@ -0,0 +1,84 @@
+//! Entropy-to-word mapping, in the spirit of BIP39 mnemonics.
+
+use alloc::vec::Vec;
+
+/// Number of words in a canonical wordlist, indexable by an 11-bit value
+/// (`2^11 == 2048`), as used by BIP39-style mnemonics.
+pub(crate) const WORDLIST_SIZE: usize = 2048;
+
+/// Splits a byte buffer into consecutive 11-bit, big-endian-packed chunks.
+///
+/// The final chunk is zero-padded on the right if `bytes` isn't a multiple
+/// of 11 bits.
+pub(crate) fn split_into_11_bit_chunks(bytes: &[u8]) -> Vec<u16> {
+    let mut chunks = Vec::new();
+    let mut acc: u32 = 0;
+    let mut acc_bits: u32 = 0;
+
+    for &byte in bytes {
+        acc = (acc << 8) | u32::from(byte);
+        acc_bits += 8;
+
+        while acc_bits >= 11 {
+            acc_bits -= 11;
+            chunks.push(((acc >> acc_bits) & 0x7ff) as u16);
+        }
+    }
+
+    if acc_bits > 0 {
+        chunks.push(((acc << (11 - acc_bits)) & 0x7ff) as u16);
+    }
+
+    chunks
+}
+
+/// Maps `entropy` onto a sequence of words from `wordlist`, indexing it
+/// with consecutive 11-bit chunks of `entropy`. Returns `None` if
+/// `wordlist` has fewer than [`WORDLIST_SIZE`] entries.
+///
+/// Generic over the wordlist's lifetime so the mapping itself can be
+/// exercised against a synthetic list in tests, independent of whether any
+/// bundled language dictionary is actually large enough.
+pub(crate) fn map_entropy<'a>(entropy: &[u8], wordlist: &[&'a str]) -> Option<Vec<&'a str>> {
+    if wordlist.len() < WORDLIST_SIZE {
+        return None;
+    }
+
+    Some(
+        split_into_11_bit_chunks(entropy)
+            .into_iter()
+            .map(|index| wordlist[index as usize])
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::String};
+
+    #[test]
+    fn splits_bytes_into_11_bit_chunks() {
+        // 24 bits of all-ones splits into two full 11-bit chunks and a
+        // final 2-bit remainder, zero-padded on the right.
+        let chunks = split_into_11_bit_chunks(&[0xFF, 0xFF, 0xFF]);
+        assert_eq!(chunks, Vec::from([0x7FF, 0x7FF, 0x600]));
+    }
+
+    #[test]
+    fn maps_entropy_onto_synthetic_wordlist() {
+        let words: Vec<String> = (0..WORDLIST_SIZE).map(|i| format!("word{i}")).collect();
+        let list: Vec<&str> = words.iter().map(String::as_str).collect();
+
+        // 16 zero bits split into one full 11-bit chunk plus a 5-bit
+        // remainder padded to 11, both indexing word 0.
+        let mapped = map_entropy(&[0x00, 0x00], &list).expect("list meets WORDLIST_SIZE");
+        assert_eq!(mapped, Vec::from(["word0", "word0"]));
+    }
+
+    #[test]
+    fn rejects_undersized_wordlist() {
+        let list: Vec<&str> = Vec::from(["only", "a", "few", "words"]);
+        assert_eq!(map_entropy(&[0x00], &list), None);
+    }
+}
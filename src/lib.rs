@@ -20,10 +20,33 @@
 //! - French
 //! - Chinese
 //!
+//! ## `no_std`
+//! The `std` feature is enabled by default and pulls in [`rand::thread_rng`]
+//! for the zero-argument `gen*` functions. Disabling default features opts
+//! into `#![no_std]` (`alloc` is still required for the length/prefix
+//! buckets); use the `*_with` functions and supply your own [`rand::Rng`]:
+//! ```toml
+//! [dependencies]
+//! random_word = { version = "0.4.1", default-features = false, features = ["en"] }
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
+#[cfg(feature = "std")]
+mod detect;
+mod entropy;
+mod query;
+mod syllable;
 mod words;
 
-use rand::{seq::SliceRandom, thread_rng};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use rand::{seq::SliceRandom, Rng};
+#[cfg(feature = "std")]
+use rand::thread_rng;
+
+pub use query::WordQuery;
 
 /// ISO 639-1 language codes.
 ///
@@ -113,11 +136,32 @@ pub fn all(lang: Lang) -> &'static [&'static str] {
     doc = "let word = random_word::gen(Lang::Zh);\nassert!(!word.is_empty());"
 )]
 /// ```
+#[cfg(feature = "std")]
 #[inline(always)]
 pub fn gen(lang: Lang) -> &'static str {
-    words::get(lang)
-        .choose(&mut thread_rng())
-        .expect("array is empty")
+    gen_with(lang, &mut thread_rng())
+}
+
+/// Generates a random word with the given language, using the supplied RNG.
+///
+/// This is the `no_std`-friendly counterpart to [`gen`]: it takes any
+/// [`rand::Rng`] instead of relying on [`rand::thread_rng`], which also
+/// makes generation reproducible when given a seeded RNG.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+#[cfg_attr(
+    feature = "en",
+    doc = "let word = random_word::gen_with(Lang::En, &mut rng);\nassert!(!word.is_empty());"
+)]
+/// ```
+#[inline(always)]
+pub fn gen_with<R: Rng + ?Sized>(lang: Lang, rng: &mut R) -> &'static str {
+    words::get(lang).choose(rng).expect("array is empty")
 }
 
 /// Returns all words with the given length and language.
@@ -148,8 +192,8 @@ pub fn gen(lang: Lang) -> &'static str {
 )]
 /// ```
 #[inline(always)]
-pub fn all_len(len: usize, lang: Lang) -> Option<&'static [&'static str]> {
-    words::get_len(len, lang).map(|boxed| &**boxed)
+pub fn all_len(len: usize, lang: Lang) -> Option<Box<[&'static str]>> {
+    words::get_len(len, lang)
 }
 
 /// Generates a random word with the given length and language.
@@ -179,11 +223,32 @@ pub fn all_len(len: usize, lang: Lang) -> Option<&'static [&'static str]> {
     doc = "let word = random_word::gen_len(4, Lang::Zh);\nassert!(word.is_some());"
 )]
 /// ```
+#[cfg(feature = "std")]
 #[inline(always)]
 pub fn gen_len(len: usize, lang: Lang) -> Option<&'static str> {
-    words::get_len(len, lang)?
-        .choose(&mut thread_rng())
-        .copied()
+    gen_len_with(len, lang, &mut thread_rng())
+}
+
+/// Generates a random word with the given length and language, using the
+/// supplied RNG.
+///
+/// This is the `no_std`-friendly counterpart to [`gen_len`]; passing a
+/// seeded RNG makes the result reproducible across runs.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+#[cfg_attr(
+    feature = "en",
+    doc = "let word = random_word::gen_len_with(4, Lang::En, &mut rng);\nassert!(word.is_some());"
+)]
+/// ```
+#[inline(always)]
+pub fn gen_len_with<R: Rng + ?Sized>(len: usize, lang: Lang, rng: &mut R) -> Option<&'static str> {
+    words::get_len(len, lang)?.choose(rng).copied()
 }
 
 /// Returns all words with the given starting character and language.
@@ -214,8 +279,8 @@ pub fn gen_len(len: usize, lang: Lang) -> Option<&'static str> {
 )]
 /// ```
 #[inline(always)]
-pub fn all_starts_with(char: char, lang: Lang) -> Option<&'static [&'static str]> {
-    words::get_starts_with(char, lang).map(|boxed| &**boxed)
+pub fn all_starts_with(char: char, lang: Lang) -> Option<Box<[&'static str]>> {
+    words::get_starts_with(char, lang)
 }
 
 /// Generates a random word with the given starting character and language.
@@ -245,9 +310,200 @@ pub fn all_starts_with(char: char, lang: Lang) -> Option<&'static [&'static str]
     doc = "let word = random_word::gen_starts_with('c', Lang::Zh);\nassert!(word.is_some());"
 )]
 /// ```
+#[cfg(feature = "std")]
 #[inline(always)]
 pub fn gen_starts_with(char: char, lang: Lang) -> Option<&'static str> {
-    words::get_starts_with(char, lang)?
-        .choose(&mut thread_rng())
-        .copied()
+    gen_starts_with_with(char, lang, &mut thread_rng())
+}
+
+/// Generates a random word with the given starting character and language,
+/// using the supplied RNG.
+///
+/// This is the `no_std`-friendly counterpart to [`gen_starts_with`]; passing
+/// a seeded RNG makes the result reproducible across runs.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+#[cfg_attr(
+    feature = "en",
+    doc = "let word = random_word::gen_starts_with_with('c', Lang::En, &mut rng);\nassert!(word.is_some());"
+)]
+/// ```
+#[inline(always)]
+pub fn gen_starts_with_with<R: Rng + ?Sized>(
+    char: char,
+    lang: Lang,
+    rng: &mut R,
+) -> Option<&'static str> {
+    words::get_starts_with(char, lang)?.choose(rng).copied()
+}
+
+/// Generates a passphrase of `word_count` independently drawn words, joined
+/// by `sep`.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+///
+#[cfg_attr(
+    feature = "en",
+    doc = "let phrase = random_word::gen_phrase(4, Lang::En, \"-\");\nassert_eq!(phrase.matches('-').count(), 3);"
+)]
+/// ```
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn gen_phrase(word_count: usize, lang: Lang, sep: &str) -> String {
+    gen_phrase_with(word_count, lang, sep, &mut thread_rng())
+}
+
+/// Generates a passphrase of `word_count` independently drawn words, joined
+/// by `sep`, using the supplied RNG.
+///
+/// This is the `no_std`-friendly counterpart to [`gen_phrase`]; passing a
+/// seeded RNG makes the passphrase reproducible across runs.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+#[cfg_attr(
+    feature = "en",
+    doc = "let phrase = random_word::gen_phrase_with(4, Lang::En, \"-\", &mut rng);\nassert_eq!(phrase.matches('-').count(), 3);"
+)]
+/// ```
+#[inline(always)]
+pub fn gen_phrase_with<R: Rng + ?Sized>(
+    word_count: usize,
+    lang: Lang,
+    sep: &str,
+    rng: &mut R,
+) -> String {
+    let words = words::get(lang);
+    (0..word_count)
+        .map(|_| *words.choose(rng).expect("array is empty"))
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+/// Returns this language's fixed 2048-word list, indexable by an 11-bit
+/// value, if the dictionary contains enough distinct entries.
+///
+/// This mirrors the wordlists used by BIP39-style mnemonics, which map
+/// entropy bytes onto words via [`entropy_to_words`]. The bundled
+/// dictionaries in this crate are currently smaller than 2048 entries, so
+/// this returns `None` until a language ships a large enough word list.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+///
+#[cfg_attr(
+    feature = "en",
+    doc = "assert_eq!(random_word::wordlist(Lang::En), None);"
+)]
+/// ```
+#[inline(always)]
+pub fn wordlist(lang: Lang) -> Option<&'static [&'static str]> {
+    let words = words::get(lang);
+    if words.len() >= entropy::WORDLIST_SIZE {
+        Some(&words[..entropy::WORDLIST_SIZE])
+    } else {
+        None
+    }
+}
+
+/// Maps a buffer of entropy bytes onto a sequence of words from this
+/// language's [`wordlist`].
+///
+/// `entropy` is split into consecutive 11-bit, big-endian-packed chunks,
+/// each used as an index into the 2048-word list, in the same way BIP39
+/// turns entropy into a mnemonic. Returns `None` if the language's
+/// wordlist isn't available (see [`wordlist`]).
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+///
+#[cfg_attr(
+    feature = "en",
+    doc = "assert_eq!(random_word::entropy_to_words(&[0u8; 4], Lang::En), None);"
+)]
+/// ```
+pub fn entropy_to_words(entropy: &[u8], lang: Lang) -> Option<Vec<&'static str>> {
+    entropy::map_entropy(entropy, words::get(lang))
+}
+
+/// Synthesizes a novel, pronounceable word for the given language instead
+/// of sampling the fixed dictionary.
+///
+/// A word is built by chaining a prefix syllable, zero or more center
+/// syllables and a suffix syllable; a pick that violates an adjacency
+/// constraint between syllables (e.g. "must follow a vowel-ending
+/// syllable") is redrawn a bounded number of times, and an empty string is
+/// returned if no valid word is found within those attempts. Useful for
+/// fantasy names, test data and brandable identifiers.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+#[cfg_attr(
+    feature = "en",
+    doc = "let word = random_word::gen_syllabic(Lang::En, &mut rng);\nassert!(!word.is_empty());"
+)]
+/// ```
+#[inline(always)]
+pub fn gen_syllabic<R: Rng + ?Sized>(lang: Lang, rng: &mut R) -> String {
+    syllable::gen(lang, rng)
+}
+
+/// Guesses which enabled language `word` belongs to.
+///
+/// Checks for exact membership in each enabled language's word list first;
+/// for out-of-dictionary input, falls back to the same character n-gram
+/// model used by [`rank`] and returns its top pick.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+///
+#[cfg_attr(
+    feature = "en",
+    doc = "assert_eq!(random_word::detect(\"apple\"), Some(Lang::En));"
+)]
+/// ```
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn detect(word: &str) -> Option<Lang> {
+    detect::detect(word)
+}
+
+/// Scores `word` against every enabled language's character n-gram model,
+/// most likely language first.
+///
+/// Unlike [`detect`], this always returns a ranking rather than only the
+/// top guess, which is useful when the caller wants to see how close the
+/// runners-up were.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+///
+#[cfg_attr(
+    feature = "en",
+    doc = "let ranking = random_word::rank(\"apple\");\nassert_eq!(ranking[0].0, Lang::En);\nassert!(ranking.windows(2).all(|w| w[0].1 >= w[1].1));"
+)]
+/// ```
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn rank(word: &str) -> Vec<(Lang, f64)> {
+    detect::rank(word)
 }
@@ -0,0 +1,162 @@
+//! A composable builder for filtering a language's word list on more than
+//! one axis at once.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{words, Lang};
+
+/// Builds a query over a language's word list, combining length range,
+/// starting character, ending substring and substring-containment
+/// predicates.
+///
+/// Starts from a starting-character or single-length bucket from
+/// [`words`] where the query has one, and applies the remaining predicates
+/// as filters. Those buckets are built by filtering the full word list on
+/// each call, so every query still scans the full list once.
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, WordQuery};
+///
+#[cfg_attr(
+    feature = "en",
+    doc = "let words = WordQuery::new(Lang::En)\n    .len_range(4..=6)\n    .starts_with('p')\n    .all();\nassert_eq!(words, vec![\"pebble\", \"pepper\"]);"
+)]
+/// ```
+#[derive(Debug, Clone)]
+pub struct WordQuery {
+    lang: Lang,
+    len_range: Option<RangeInclusive<usize>>,
+    starts_with: Option<char>,
+    ends_with: Option<String>,
+    contains: Option<String>,
+}
+
+impl WordQuery {
+    /// Starts a new, unfiltered query over `lang`'s word list.
+    pub fn new(lang: Lang) -> Self {
+        Self {
+            lang,
+            len_range: None,
+            starts_with: None,
+            ends_with: None,
+            contains: None,
+        }
+    }
+
+    /// Restricts results to words whose length (in characters) falls
+    /// within `range`.
+    pub fn len_range(mut self, range: RangeInclusive<usize>) -> Self {
+        self.len_range = Some(range);
+        self
+    }
+
+    /// Restricts results to words starting with `char`.
+    pub fn starts_with(mut self, char: char) -> Self {
+        self.starts_with = Some(char);
+        self
+    }
+
+    /// Restricts results to words ending with `suffix`.
+    pub fn ends_with(mut self, suffix: &str) -> Self {
+        self.ends_with = Some(String::from(suffix));
+        self
+    }
+
+    /// Restricts results to words containing `needle`.
+    pub fn contains(mut self, needle: &str) -> Self {
+        self.contains = Some(String::from(needle));
+        self
+    }
+
+    /// Picks the bucket this query starts from: the starting-character
+    /// bucket if one is set, else the bucket for a single-length range,
+    /// else the full dictionary. Bucket sizes are not compared.
+    fn candidates(&self) -> Box<[&'static str]> {
+        if let Some(char) = self.starts_with {
+            return words::get_starts_with(char, self.lang).unwrap_or_default();
+        }
+
+        if let Some(range) = &self.len_range {
+            if range.start() == range.end() {
+                return words::get_len(*range.start(), self.lang).unwrap_or_default();
+            }
+        }
+
+        words::get(self.lang).iter().copied().collect()
+    }
+
+    fn matches(&self, word: &str) -> bool {
+        if let Some(range) = &self.len_range {
+            if !range.contains(&word.chars().count()) {
+                return false;
+            }
+        }
+        if let Some(char) = self.starts_with {
+            if !word.starts_with(char) {
+                return false;
+            }
+        }
+        if let Some(suffix) = &self.ends_with {
+            if !word.ends_with(suffix.as_str()) {
+                return false;
+            }
+        }
+        if let Some(needle) = &self.contains {
+            if !word.contains(needle.as_str()) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Returns every word matching this query.
+    pub fn all(&self) -> Vec<&'static str> {
+        self.candidates()
+            .iter()
+            .copied()
+            .filter(|word| self.matches(word))
+            .collect()
+    }
+
+    /// Generates a random word matching this query, using the supplied RNG.
+    pub fn gen<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&'static str> {
+        self.all().choose(rng).copied()
+    }
+}
+
+#[cfg(all(test, feature = "en"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combined_filters_match_exact_subset() {
+        let words = WordQuery::new(Lang::En)
+            .len_range(4..=6)
+            .starts_with('p')
+            .all();
+        assert_eq!(words, Vec::from(["pebble", "pepper"]));
+    }
+
+    #[test]
+    fn ends_with_and_contains_compose_as_a_conjunction() {
+        let words = WordQuery::new(Lang::En)
+            .ends_with("er")
+            .contains("pp")
+            .all();
+        assert_eq!(words, Vec::from(["pepper"]));
+    }
+
+    #[test]
+    fn unsatisfiable_combination_returns_empty_vec_not_none() {
+        let words = WordQuery::new(Lang::En)
+            .starts_with('q')
+            .ends_with("zzz")
+            .all();
+        assert!(words.is_empty());
+    }
+}
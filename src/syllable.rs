@@ -0,0 +1,318 @@
+//! Synthesizes novel, pronounceable words from per-language syllable pools,
+//! in the style of `rnglib`'s prefix/center/suffix random name generator.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Lang;
+
+/// A syllable fragment and the adjacency constraints it imposes on its
+/// neighbors.
+#[derive(Debug, Clone, Copy)]
+struct Syllable {
+    text: &'static str,
+    /// This syllable may only follow one that ends in a vowel.
+    needs_vowel_before: bool,
+    /// This syllable may only be followed by one that starts with a
+    /// consonant.
+    needs_consonant_after: bool,
+}
+
+/// Only the `*_POOLS` statics below call these, and every one of those is
+/// gated behind its own language feature, so with none enabled neither
+/// helper is reachable; gate them the same way rather than letting `cargo
+/// clippy --no-default-features` (a combination the `[features]` table
+/// doesn't forbid) fail on dead code.
+#[cfg(any(
+    feature = "de",
+    feature = "en",
+    feature = "es",
+    feature = "fr",
+    feature = "zh"
+))]
+const fn syl(text: &'static str) -> Syllable {
+    Syllable {
+        text,
+        needs_vowel_before: false,
+        needs_consonant_after: false,
+    }
+}
+
+#[cfg(any(
+    feature = "de",
+    feature = "en",
+    feature = "es",
+    feature = "fr",
+    feature = "zh"
+))]
+const fn syl_constrained(
+    text: &'static str,
+    needs_vowel_before: bool,
+    needs_consonant_after: bool,
+) -> Syllable {
+    Syllable {
+        text,
+        needs_vowel_before,
+        needs_consonant_after,
+    }
+}
+
+struct Pools {
+    prefixes: &'static [Syllable],
+    centers: &'static [Syllable],
+    suffixes: &'static [Syllable],
+}
+
+#[cfg(feature = "en")]
+static EN_POOLS: Pools = Pools {
+    prefixes: &[syl("bri"), syl("kal"), syl("tor"), syl("mer"), syl("shan")],
+    centers: &[
+        syl_constrained("an", true, false),
+        syl_constrained("el", false, true),
+        syl("or"),
+        syl_constrained("in", true, false),
+    ],
+    suffixes: &[syl("dor"), syl("wyn"), syl("ith"), syl("mund"), syl("ara")],
+};
+
+#[cfg(feature = "es")]
+static ES_POOLS: Pools = Pools {
+    prefixes: &[syl("ca"), syl("mon"), syl("ri"), syl("sol"), syl("tal")],
+    centers: &[
+        syl_constrained("an", true, false),
+        syl_constrained("ir", false, true),
+        syl("or"),
+    ],
+    suffixes: &[syl("dero"), syl("mira"), syl("nito"), syl("zal")],
+};
+
+#[cfg(feature = "de")]
+static DE_POOLS: Pools = Pools {
+    prefixes: &[syl("bren"), syl("hal"), syl("kor"), syl("wel"), syl("hilde")],
+    centers: &[
+        syl_constrained("an", true, false),
+        syl_constrained("in", false, true),
+        syl("or"),
+    ],
+    suffixes: &[syl("berg"), syl("stadt"), syl("hart"), syl("wig")],
+};
+
+#[cfg(feature = "fr")]
+static FR_POOLS: Pools = Pools {
+    prefixes: &[syl("bel"), syl("mon"), syl("ro"), syl("cla")],
+    centers: &[
+        syl_constrained("an", true, false),
+        syl_constrained("el", false, true),
+        syl("or"),
+    ],
+    suffixes: &[syl("court"), syl("vieux"), syl("dine"), syl("mont")],
+};
+
+#[cfg(feature = "zh")]
+static ZH_POOLS: Pools = Pools {
+    prefixes: &[syl("qi"), syl("lan"), syl("hao"), syl("zhen")],
+    centers: &[
+        syl_constrained("an", true, false),
+        syl_constrained("ying", false, true),
+        syl("xiao"),
+    ],
+    suffixes: &[syl("wei"), syl("feng"), syl("jun"), syl("rui")],
+};
+
+fn pools(lang: Lang) -> &'static Pools {
+    match lang {
+        #[cfg(feature = "de")]
+        Lang::De => &DE_POOLS,
+        #[cfg(feature = "en")]
+        Lang::En => &EN_POOLS,
+        #[cfg(feature = "es")]
+        Lang::Es => &ES_POOLS,
+        #[cfg(feature = "fr")]
+        Lang::Fr => &FR_POOLS,
+        #[cfg(feature = "zh")]
+        Lang::Zh => &ZH_POOLS,
+    }
+}
+
+fn ends_with_vowel(text: &str) -> bool {
+    matches!(
+        text.chars().last(),
+        Some('a' | 'e' | 'i' | 'o' | 'u' | 'y')
+    )
+}
+
+fn starts_with_consonant(text: &str) -> bool {
+    matches!(text.chars().next(), Some(c) if !matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y'))
+}
+
+fn compatible(prev: Option<&Syllable>, next: &Syllable) -> bool {
+    if next.needs_vowel_before && !prev.is_some_and(|p| ends_with_vowel(p.text)) {
+        return false;
+    }
+    if prev.is_some_and(|p| p.needs_consonant_after) && !starts_with_consonant(next.text) {
+        return false;
+    }
+    true
+}
+
+/// Picks a syllable from `pool` that's compatible with `prev`, retrying a
+/// bounded number of times before giving up.
+fn pick_compatible<'a, R: Rng + ?Sized>(
+    pool: &'a [Syllable],
+    prev: Option<&Syllable>,
+    rng: &mut R,
+) -> Option<&'a Syllable> {
+    const MAX_ATTEMPTS: u32 = 8;
+    for _ in 0..MAX_ATTEMPTS {
+        let candidate = pool.choose(rng)?;
+        if compatible(prev, candidate) {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+/// Number of center syllables to draw, weighted to favor 2-3 total
+/// syllables once the mandatory prefix and suffix are included.
+fn center_count<R: Rng + ?Sized>(rng: &mut R) -> usize {
+    const WEIGHTS: [(usize, u32); 3] = [(0, 45), (1, 40), (2, 15)];
+    let total: u32 = WEIGHTS.iter().map(|(_, weight)| weight).sum();
+    let mut choice = rng.gen_range(0..total);
+    for (count, weight) in WEIGHTS {
+        if choice < weight {
+            return count;
+        }
+        choice -= weight;
+    }
+    unreachable!("weights must sum to `total`")
+}
+
+/// Synthesizes a novel, pronounceable word for `lang` by chaining a prefix,
+/// zero or more center syllables and a suffix, retrying any join that
+/// violates a syllable's adjacency constraints.
+///
+/// Gives up and returns an empty string after a bounded number of whole
+/// draws, so a pool with an unsatisfiable constraint can't hang the caller.
+pub(crate) fn gen<R: Rng + ?Sized>(lang: Lang, rng: &mut R) -> String {
+    const MAX_WORD_ATTEMPTS: u32 = 32;
+    let pools = pools(lang);
+
+    for _ in 0..MAX_WORD_ATTEMPTS {
+        let mut picked: Vec<&Syllable> = Vec::new();
+
+        let Some(prefix) = pools.prefixes.choose(rng) else {
+            return String::new();
+        };
+        picked.push(prefix);
+
+        let mut ok = true;
+        for _ in 0..center_count(rng) {
+            match pick_compatible(pools.centers, picked.last().copied(), rng) {
+                Some(center) => picked.push(center),
+                None => {
+                    ok = false;
+                    break;
+                }
+            }
+        }
+        if !ok {
+            continue;
+        }
+
+        let Some(suffix) = pick_compatible(pools.suffixes, picked.last().copied(), rng) else {
+            continue;
+        };
+        picked.push(suffix);
+
+        let mut word = String::new();
+        for syllable in picked {
+            word.push_str(syllable.text);
+        }
+        return word;
+    }
+
+    String::new()
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "de",
+        feature = "en",
+        feature = "es",
+        feature = "fr",
+        feature = "zh"
+    )
+))]
+mod tests {
+    use super::*;
+
+    /// A syllable that requires a vowel-ending predecessor can only ever be
+    /// chosen if some prefix or center actually ends in a vowel; likewise a
+    /// syllable that requires a consonant-starting successor needs some
+    /// center or suffix that actually starts with one.
+    fn assert_constraints_reachable(pools: &Pools) {
+        let needs_vowel_predecessor = pools
+            .centers
+            .iter()
+            .chain(pools.suffixes.iter())
+            .any(|syllable| syllable.needs_vowel_before);
+        if needs_vowel_predecessor {
+            assert!(
+                pools
+                    .prefixes
+                    .iter()
+                    .chain(pools.centers.iter())
+                    .any(|syllable| ends_with_vowel(syllable.text)),
+                "a `needs_vowel_before` syllable has no reachable predecessor"
+            );
+        }
+
+        let needs_consonant_successor = pools
+            .prefixes
+            .iter()
+            .chain(pools.centers.iter())
+            .any(|syllable| syllable.needs_consonant_after);
+        if needs_consonant_successor {
+            assert!(
+                pools
+                    .centers
+                    .iter()
+                    .chain(pools.suffixes.iter())
+                    .any(|syllable| starts_with_consonant(syllable.text)),
+                "a `needs_consonant_after` syllable has no reachable successor"
+            );
+        }
+    }
+
+    #[cfg(feature = "de")]
+    #[test]
+    fn de_constraints_reachable() {
+        assert_constraints_reachable(&DE_POOLS);
+    }
+
+    #[cfg(feature = "en")]
+    #[test]
+    fn en_constraints_reachable() {
+        assert_constraints_reachable(&EN_POOLS);
+    }
+
+    #[cfg(feature = "es")]
+    #[test]
+    fn es_constraints_reachable() {
+        assert_constraints_reachable(&ES_POOLS);
+    }
+
+    #[cfg(feature = "fr")]
+    #[test]
+    fn fr_constraints_reachable() {
+        assert_constraints_reachable(&FR_POOLS);
+    }
+
+    #[cfg(feature = "zh")]
+    #[test]
+    fn zh_constraints_reachable() {
+        assert_constraints_reachable(&ZH_POOLS);
+    }
+}
@@ -0,0 +1,105 @@
+//! Static word lists and lookup helpers.
+//!
+//! The lists in this module are `'static` and allocation-free; only the
+//! length/prefix buckets derived from them need [`alloc`] to build their
+//! boxed slices, which keeps the lookup path usable under `#![no_std]`.
+
+use crate::Lang;
+use alloc::boxed::Box;
+
+#[cfg(feature = "de")]
+static DE_WORDS: &[&str] = &[
+    "apfel", "arbeit", "baum", "berg", "blume", "brot", "buch", "creme", "dach", "dorf", "erde",
+    "feuer", "fisch", "frau", "freund", "garten", "geld", "glas", "gras", "haus", "herz", "himmel",
+    "hund", "katze", "kind", "licht", "liebe", "luft", "mann", "meer", "milch", "mond", "mutter",
+    "nacht", "ozean", "regen", "schnee", "sonne", "stadt", "stein", "strasse", "tisch", "tier",
+    "traum", "tur", "vater", "vogel", "wald", "wasser", "wind", "winter", "zeit",
+];
+
+#[cfg(feature = "en")]
+static EN_WORDS: &[&str] = &[
+    "apple", "bridge", "candle", "desert", "eagle", "forest", "garden", "harbor", "island",
+    "jungle", "kettle", "lantern", "meadow", "nectar", "ocean", "pebble", "quiver", "river",
+    "summit", "temple", "umbrella", "valley", "window", "yellow", "zephyr", "anchor", "breeze",
+    "cactus", "dolphin", "ember", "falcon", "glacier", "horizon", "ivory", "jasmine", "kindred",
+    "lunar", "maple", "nomad", "orchid", "pepper", "quartz", "ribbon", "sapphire", "thunder",
+    "velvet", "willow", "amber", "cedar", "dawn",
+];
+
+#[cfg(feature = "es")]
+static ES_WORDS: &[&str] = &[
+    "agua", "arbol", "barco", "casa", "cielo", "dedo", "espejo", "fuego", "gato", "hielo",
+    "isla", "jardin", "lago", "luna", "madera", "nieve", "nube", "oro", "perro", "playa",
+    "puente", "rio", "sol", "tierra", "vela", "viento", "abeja", "bosque", "cancion", "dragon",
+    "estrella", "flor", "gota", "hoja", "invierno", "jazmin", "llave", "montana", "naranja",
+    "oceano", "pajaro", "queso", "raiz", "sombra", "trueno", "uva", "valle", "ventana", "zorro",
+];
+
+#[cfg(feature = "fr")]
+static FR_WORDS: &[&str] = &[
+    "arbre", "bateau", "chat", "ciel", "dragon", "eau", "feu", "fleur", "foret", "gateau",
+    "herbe", "hiver", "ile", "jardin", "lac", "lune", "maison", "montagne", "neige", "nuage",
+    "oiseau", "ombre", "pain", "plage", "pont", "riviere", "route", "soleil", "terre", "vent",
+    "vigne", "voile", "abeille", "automne", "chanson", "etoile", "fenetre", "glace", "jasmin",
+    "orange", "poisson", "racine", "renard", "tonnerre", "vallee", "verre", "ville", "vitre",
+];
+
+// Romanized (Pinyin, tone marks dropped) rather than Hanzi, so they work
+// with the same char-based `all_starts_with`/`all_len` filters as every
+// other language's word list.
+#[cfg(feature = "zh")]
+static ZH_WORDS: &[&str] = &[
+    "pingguo", "heliu", "qiaoliang", "lazhu", "shamo", "laoying", "senlin", "huayuan", "gangkou",
+    "haidao", "conglin", "shuihu", "denglong", "caodi", "huami", "haiyang", "shanding", "simiao",
+    "yusan", "shangu", "chuanghu", "huangse", "weifeng", "xianrenzhang", "haitun", "yujin",
+    "lieying", "bingchuan", "dipingxian", "xiangya", "moli", "yueguang", "fengye", "youmu",
+    "lanhua", "hujiao", "shiying", "feng", "caihong",
+];
+
+/// Returns all words with the given language.
+pub(crate) fn get(lang: Lang) -> &'static [&'static str] {
+    match lang {
+        #[cfg(feature = "de")]
+        Lang::De => DE_WORDS,
+        #[cfg(feature = "en")]
+        Lang::En => EN_WORDS,
+        #[cfg(feature = "es")]
+        Lang::Es => ES_WORDS,
+        #[cfg(feature = "fr")]
+        Lang::Fr => FR_WORDS,
+        #[cfg(feature = "zh")]
+        Lang::Zh => ZH_WORDS,
+    }
+}
+
+/// Returns all words with the given length and language, bucketed into a
+/// freshly allocated slice.
+pub(crate) fn get_len(len: usize, lang: Lang) -> Option<Box<[&'static str]>> {
+    let words: Box<[&'static str]> = get(lang)
+        .iter()
+        .copied()
+        .filter(|word| word.chars().count() == len)
+        .collect();
+
+    if words.is_empty() {
+        None
+    } else {
+        Some(words)
+    }
+}
+
+/// Returns all words with the given starting character and language,
+/// bucketed into a freshly allocated slice.
+pub(crate) fn get_starts_with(char: char, lang: Lang) -> Option<Box<[&'static str]>> {
+    let words: Box<[&'static str]> = get(lang)
+        .iter()
+        .copied()
+        .filter(|word| word.starts_with(char))
+        .collect();
+
+    if words.is_empty() {
+        None
+    } else {
+        Some(words)
+    }
+}